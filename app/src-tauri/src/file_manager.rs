@@ -1,13 +1,24 @@
 use std::path::Path;
-use tauri::State;
+use tauri::{Emitter, State, Window};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use tauri_plugin_dialog::DialogExt;
 use std::sync::Mutex;
+use std::time::Duration;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+#[cfg(target_os = "linux")]
+use dbus::blocking::SyncConnection;
+
+/// Debounce window for coalescing filesystem events before re-listing a folder.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(400);
 
 #[derive(Default)]
 pub struct FileManagerState {
     pub default_md_folder: Mutex<String>,
+    pub watcher: Mutex<Option<RecommendedWatcher>>,
+    #[cfg(target_os = "linux")]
+    pub dbus_conn: Mutex<Option<SyncConnection>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -16,6 +27,9 @@ pub struct FileOperation {
     pub name: String,
     pub size: u64,
     pub is_dir: bool,
+    /// Populated only by `read_tree`; `None` for flat listings like `list_files`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<FileOperation>>,
 }
 
 /// Get the default markdown folder path
@@ -34,10 +48,21 @@ pub fn get_default_md_folder(state: State<FileManagerState>) -> Result<String, S
 
 /// Set the default markdown folder
 #[tauri::command]
-pub fn set_default_md_folder(folder_path: String, state: State<FileManagerState>) -> Result<(), String> {
+pub fn set_default_md_folder(
+    folder_path: String,
+    window: Window,
+    state: State<FileManagerState>,
+) -> Result<(), String> {
     if Path::new(&folder_path).is_dir() {
         let mut folder = state.default_md_folder.lock().map_err(|e| e.to_string())?;
-        *folder = folder_path;
+        *folder = folder_path.clone();
+        drop(folder);
+
+        // Re-point the watcher at the new folder if one is already running.
+        if state.watcher.lock().map_err(|e| e.to_string())?.is_some() {
+            start_watching(folder_path, window, state)?;
+        }
+
         Ok(())
     } else {
         Err("Invalid folder path".to_string())
@@ -100,18 +125,58 @@ pub fn open_folder_dialog(window: tauri::Window) -> Result<Option<String>, Strin
     }))
 }
 
-/// Read file contents
+/// Read file contents as UTF-8 text. Use `read_file_binary` for files that
+/// aren't valid UTF-8.
 #[tauri::command]
 pub fn read_file(file_path: String) -> Result<String, String> {
-    fs::read_to_string(&file_path)
-        .map_err(|e| format!("Error reading file: {}", e))
+    let bytes = fs::read(&file_path).map_err(|e| format!("Error reading file: {}", e))?;
+    String::from_utf8(bytes)
+        .map_err(|_| "File is not valid UTF-8; use read_file_binary instead".to_string())
 }
 
-/// Write file contents
+/// Read raw file contents, for files that aren't UTF-8 text.
 #[tauri::command]
-pub fn write_file(file_path: String, content: String) -> Result<(), String> {
-    fs::write(&file_path, content)
-        .map_err(|e| format!("Error writing file: {}", e))
+pub fn read_file_binary(file_path: String) -> Result<Vec<u8>, String> {
+    fs::read(&file_path).map_err(|e| format!("Error reading file: {}", e))
+}
+
+/// Prefix/suffix marking a `write_file` scratch file, so it can be filtered
+/// out of folder listings and recognized for cleanup.
+const WRITE_TEMP_PREFIX: &str = ".udos-write-";
+const WRITE_TEMP_SUFFIX: &str = ".tmp";
+
+/// True if `name` looks like a `write_file` scratch file rather than a real
+/// user file, so listings don't show a save that's still in flight.
+fn is_write_temp_file(name: &str) -> bool {
+    name.starts_with(WRITE_TEMP_PREFIX) && name.ends_with(WRITE_TEMP_SUFFIX)
+}
+
+/// Write file contents atomically: the new content is written to a temp file
+/// in the same directory, then renamed over the destination, so a crash
+/// mid-save can't truncate the user's document. Returns the resulting file's
+/// metadata (path, name, size) so the caller can update its size without a
+/// second round-trip; the modification time is not included.
+#[tauri::command]
+pub fn write_file(file_path: String, content: String) -> Result<FileOperation, String> {
+    let path = Path::new(&file_path);
+    let parent = path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+
+    let temp_name = format!(
+        "{}{}{}",
+        WRITE_TEMP_PREFIX,
+        path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| "write".to_string()),
+        WRITE_TEMP_SUFFIX
+    );
+    let temp_path = parent.join(temp_name);
+
+    fs::write(&temp_path, content).map_err(|e| format!("Error writing file: {}", e))?;
+
+    if let Err(e) = fs::rename(&temp_path, path) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(format!("Error saving file: {}", e));
+    }
+
+    get_file_info(file_path)
 }
 
 /// Create new markdown file with timestamp
@@ -139,12 +204,17 @@ pub fn list_files(folder_path: String) -> Result<Vec<FileOperation>, String> {
     for entry in entries {
         if let Ok(entry) = entry {
             let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if is_write_temp_file(&name) {
+                continue;
+            }
             if let Ok(metadata) = entry.metadata() {
                 files.push(FileOperation {
                     path: path.to_string_lossy().to_string(),
-                    name: entry.file_name().to_string_lossy().to_string(),
+                    name,
                     size: metadata.len(),
                     is_dir: metadata.is_dir(),
+                    children: None,
                 });
             }
         }
@@ -156,6 +226,266 @@ pub fn list_files(folder_path: String) -> Result<Vec<FileOperation>, String> {
     Ok(files)
 }
 
+/// Recursively walk `root` into a nested `FileOperation` tree, so the frontend
+/// can render a collapsible tree navigator without one IPC round-trip per folder.
+#[tauri::command]
+pub fn read_tree(
+    root: String,
+    max_depth: u32,
+    include_hidden: bool,
+    ignore: Vec<String>,
+) -> Result<Vec<FileOperation>, String> {
+    read_tree_level(Path::new(&root), max_depth, include_hidden, &ignore)
+}
+
+fn read_tree_level(
+    dir: &Path,
+    depth_remaining: u32,
+    include_hidden: bool,
+    ignore: &[String],
+) -> Result<Vec<FileOperation>, String> {
+    let mut entries = Vec::new();
+
+    let read_dir = fs::read_dir(dir).map_err(|e| format!("Error reading folder: {}", e))?;
+    for entry in read_dir {
+        let Ok(entry) = entry else { continue };
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if is_write_temp_file(&name) {
+            continue;
+        }
+        if !include_hidden && name.starts_with('.') {
+            continue;
+        }
+        if ignore.iter().any(|pattern| pattern == &name) {
+            continue;
+        }
+
+        let Ok(metadata) = entry.metadata() else { continue };
+        let path = entry.path();
+        let is_dir = metadata.is_dir();
+
+        let children = if is_dir && depth_remaining > 0 {
+            Some(read_tree_level(&path, depth_remaining - 1, include_hidden, ignore)?)
+        } else {
+            None
+        };
+
+        entries.push(FileOperation {
+            path: path.to_string_lossy().to_string(),
+            name,
+            size: metadata.len(),
+            is_dir,
+            children,
+        });
+    }
+
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+/// Start watching `folder_path` for changes, emitting a debounced `files-changed`
+/// event with the refreshed listing whenever files are added, removed, or renamed.
+#[tauri::command]
+pub fn start_watching(
+    folder_path: String,
+    window: Window,
+    state: State<FileManagerState>,
+) -> Result<(), String> {
+    use std::sync::mpsc::channel;
+
+    let (tx, rx) = channel();
+
+    let mut watcher: RecommendedWatcher = notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    })
+    .map_err(|e| format!("Error creating watcher: {}", e))?;
+
+    watcher
+        .watch(Path::new(&folder_path), RecursiveMode::NonRecursive)
+        .map_err(|e| format!("Error watching folder: {}", e))?;
+
+    // Replace any previously active watcher; dropping it stops the old watch.
+    *state.watcher.lock().map_err(|e| e.to_string())? = Some(watcher);
+
+    std::thread::spawn(move || {
+        loop {
+            // Block for the first event, then drain anything else that arrives
+            // within the debounce window so a burst of saves collapses into one refresh.
+            match rx.recv() {
+                Ok(_) => {
+                    while rx.recv_timeout(WATCH_DEBOUNCE).is_ok() {}
+                }
+                Err(_) => break, // watcher was dropped (stop_watching or replaced)
+            }
+
+            match list_files(folder_path.clone()) {
+                Ok(files) => {
+                    if window.emit("files-changed", files).is_err() {
+                        break;
+                    }
+                }
+                Err(_) => continue,
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Stop any active folder watch started by `start_watching`.
+#[tauri::command]
+pub fn stop_watching(state: State<FileManagerState>) -> Result<(), String> {
+    *state.watcher.lock().map_err(|e| e.to_string())? = None;
+    Ok(())
+}
+
+/// Return the configured default markdown folder, erroring if none is set.
+fn default_root(state: &State<FileManagerState>) -> Result<String, String> {
+    let folder = state.default_md_folder.lock().map_err(|e| e.to_string())?;
+    if folder.is_empty() {
+        Err("No default folder configured".to_string())
+    } else {
+        Ok(folder.clone())
+    }
+}
+
+/// Reject `path` if it doesn't resolve inside `root`, so file operations can't
+/// escape the configured workspace folder.
+fn ensure_within_root(path: &Path, root: &str) -> Result<(), String> {
+    let root_canon = fs::canonicalize(root).map_err(|e| format!("Invalid root folder: {}", e))?;
+
+    let candidate = if path.exists() {
+        fs::canonicalize(path).map_err(|e| format!("Invalid path: {}", e))?
+    } else {
+        let parent = path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        let parent_canon = fs::canonicalize(parent).map_err(|e| format!("Invalid path: {}", e))?;
+        let name = path.file_name().ok_or("Path has no file name")?;
+        parent_canon.join(name)
+    };
+
+    if candidate.starts_with(&root_canon) {
+        Ok(())
+    } else {
+        Err("Path escapes the configured folder".to_string())
+    }
+}
+
+/// Recursively copy a file or directory tree from `src` to `dest`.
+fn copy_recursive(src: &Path, dest: &Path) -> std::io::Result<()> {
+    if src.is_dir() {
+        fs::create_dir_all(dest)?;
+        for entry in fs::read_dir(src)? {
+            let entry = entry?;
+            let dest_child = dest.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                copy_recursive(&entry.path(), &dest_child)?;
+            } else {
+                fs::copy(entry.path(), &dest_child)?;
+            }
+        }
+    } else {
+        fs::copy(src, dest)?;
+    }
+    Ok(())
+}
+
+/// Rename a file or folder in place, keeping it in the same parent directory.
+#[tauri::command]
+pub fn rename_path(path: String, new_name: String, state: State<FileManagerState>) -> Result<FileOperation, String> {
+    let root = default_root(&state)?;
+    let src = Path::new(&path);
+    ensure_within_root(src, &root)?;
+
+    let parent = src.parent().ok_or("Path has no parent directory")?;
+    let dest = parent.join(&new_name);
+    ensure_within_root(&dest, &root)?;
+
+    if dest.exists() {
+        return Err("A file or folder with that name already exists".to_string());
+    }
+
+    fs::rename(src, &dest).map_err(|e| format!("Error renaming: {}", e))?;
+    get_file_info(dest.to_string_lossy().to_string())
+}
+
+/// Move a file or folder into `dest_dir`, keeping its current name.
+#[tauri::command]
+pub fn move_path(
+    src: String,
+    dest_dir: String,
+    overwrite: Option<bool>,
+    state: State<FileManagerState>,
+) -> Result<FileOperation, String> {
+    let root = default_root(&state)?;
+    let src_path = Path::new(&src);
+    ensure_within_root(src_path, &root)?;
+
+    let file_name = src_path.file_name().ok_or("Source path has no file name")?;
+    let dest_path = Path::new(&dest_dir).join(file_name);
+    ensure_within_root(&dest_path, &root)?;
+
+    if dest_path.exists() && !overwrite.unwrap_or(false) {
+        return Err("Destination already exists".to_string());
+    }
+
+    fs::rename(src_path, &dest_path).map_err(|e| format!("Error moving: {}", e))?;
+    get_file_info(dest_path.to_string_lossy().to_string())
+}
+
+/// Copy a file or folder into `dest_dir`, keeping its current name.
+#[tauri::command]
+pub fn copy_path(
+    src: String,
+    dest_dir: String,
+    overwrite: Option<bool>,
+    state: State<FileManagerState>,
+) -> Result<FileOperation, String> {
+    let root = default_root(&state)?;
+    let src_path = Path::new(&src);
+    ensure_within_root(src_path, &root)?;
+
+    let file_name = src_path.file_name().ok_or("Source path has no file name")?;
+    let dest_path = Path::new(&dest_dir).join(file_name);
+    ensure_within_root(&dest_path, &root)?;
+
+    if dest_path.exists() && !overwrite.unwrap_or(false) {
+        return Err("Destination already exists".to_string());
+    }
+
+    copy_recursive(src_path, &dest_path).map_err(|e| format!("Error copying: {}", e))?;
+    get_file_info(dest_path.to_string_lossy().to_string())
+}
+
+/// Delete a file or folder (recursively for directories).
+#[tauri::command]
+pub fn delete_path(path: String, state: State<FileManagerState>) -> Result<(), String> {
+    let root = default_root(&state)?;
+    let target = Path::new(&path);
+    ensure_within_root(target, &root)?;
+
+    if target.is_dir() {
+        fs::remove_dir_all(target).map_err(|e| format!("Error deleting folder: {}", e))
+    } else {
+        fs::remove_file(target).map_err(|e| format!("Error deleting file: {}", e))
+    }
+}
+
+/// Create a new, empty folder named `name` inside `parent`.
+#[tauri::command]
+pub fn create_folder(parent: String, name: String, state: State<FileManagerState>) -> Result<FileOperation, String> {
+    let root = default_root(&state)?;
+    let dest = Path::new(&parent).join(&name);
+    ensure_within_root(&dest, &root)?;
+
+    if dest.exists() {
+        return Err("A file or folder with that name already exists".to_string());
+    }
+
+    fs::create_dir(&dest).map_err(|e| format!("Error creating folder: {}", e))?;
+    get_file_info(dest.to_string_lossy().to_string())
+}
+
 /// Get file metadata
 #[tauri::command]
 pub fn get_file_info(file_path: String) -> Result<FileOperation, String> {
@@ -172,12 +502,14 @@ pub fn get_file_info(file_path: String) -> Result<FileOperation, String> {
         name: file_name,
         size: metadata.len(),
         is_dir: metadata.is_dir(),
+        children: None,
     })
 }
 
-/// Open file in default application (Finder on macOS)
+/// Reveal `file_path` in the platform's file manager, selecting it if possible.
 #[tauri::command]
-pub fn open_in_finder(file_path: String) -> Result<(), String> {
+#[cfg_attr(not(target_os = "linux"), allow(unused_variables))]
+pub fn open_in_finder(file_path: String, state: State<FileManagerState>) -> Result<(), String> {
     #[cfg(target_os = "macos")]
     {
         std::process::Command::new("open")
@@ -186,19 +518,291 @@ pub fn open_in_finder(file_path: String) -> Result<(), String> {
             .output()
             .map_err(|e| format!("Error opening Finder: {}", e))?;
     }
-    
-    #[cfg(not(target_os = "macos"))]
+
+    #[cfg(target_os = "windows")]
     {
-        // For non-macOS, try opening the folder
-        std::process::Command::new("open")
-            .arg(&file_path)
-            .output()
-            .map_err(|e| format!("Error opening folder: {}", e))?;
+        std::process::Command::new("explorer")
+            .arg(format!("/select,{}", file_path))
+            .spawn()
+            .map_err(|e| format!("Error opening Explorer: {}", e))?;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        reveal_in_file_manager_linux(&file_path, &state)?;
     }
 
     Ok(())
 }
 
+/// Characters left unescaped in the path component of a `file://` URI.
+#[cfg(target_os = "linux")]
+const FILE_URI_PATH_SET: &percent_encoding::AsciiSet = &percent_encoding::NON_ALPHANUMERIC
+    .remove(b'/')
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Ask the freedesktop `FileManager1` D-Bus interface to highlight `file_path`,
+/// falling back to `xdg-open` on the parent directory if that isn't possible.
+#[cfg(target_os = "linux")]
+fn reveal_in_file_manager_linux(file_path: &str, state: &State<FileManagerState>) -> Result<(), String> {
+    use dbus::blocking::SyncConnection;
+    use percent_encoding::utf8_percent_encode;
+    use std::time::Duration;
+
+    // `ShowItems` has a known bug where a comma in the path breaks argument
+    // parsing, so route those straight to the xdg-open fallback.
+    if file_path.contains(',') {
+        return open_parent_with_xdg_open(file_path);
+    }
+
+    let uri = format!("file://{}", utf8_percent_encode(file_path, FILE_URI_PATH_SET));
+    let mut conn_guard = state.dbus_conn.lock().map_err(|e| e.to_string())?;
+
+    if conn_guard.is_none() {
+        *conn_guard = SyncConnection::new_session().ok();
+    }
+
+    let Some(conn) = conn_guard.as_ref() else {
+        drop(conn_guard);
+        return open_parent_with_xdg_open(file_path);
+    };
+
+    let proxy = conn.with_proxy(
+        "org.freedesktop.FileManager1",
+        "/org/freedesktop/FileManager1",
+        Duration::from_secs(5),
+    );
+
+    let result: Result<(), dbus::Error> =
+        proxy.method_call("org.freedesktop.FileManager1", "ShowItems", (vec![uri], ""));
+
+    match result {
+        Ok(()) => Ok(()),
+        Err(_) => {
+            drop(conn_guard);
+            open_parent_with_xdg_open(file_path)
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn open_parent_with_xdg_open(file_path: &str) -> Result<(), String> {
+    let parent = Path::new(file_path)
+        .parent()
+        .ok_or_else(|| "File has no parent directory".to_string())?;
+
+    std::process::Command::new("xdg-open")
+        .arg(parent)
+        .spawn()
+        .map_err(|e| format!("Error opening folder: {}", e))?;
+
+    Ok(())
+}
+
+/// An application capable of opening a file, as returned by `get_open_with_apps`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AppInfo {
+    /// Bundle id (macOS), desktop entry id (Linux), or ProgId (Windows).
+    pub id: String,
+    pub name: String,
+}
+
+/// List applications capable of opening `file_path`, for a right-click "Open With" menu.
+#[tauri::command]
+pub fn get_open_with_apps(file_path: String) -> Result<Vec<AppInfo>, String> {
+    #[cfg(target_os = "macos")]
+    {
+        open_with_macos::list_apps(&file_path)
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        open_with_linux::list_apps(&file_path)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        open_with_windows::list_apps(&file_path)
+    }
+}
+
+/// Launch `file_path` with the application identified by `app_id`, as returned
+/// by `get_open_with_apps`.
+#[tauri::command]
+pub fn open_with(file_path: String, app_id: String) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        open_with_macos::launch(&file_path, &app_id)
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        open_with_linux::launch(&file_path, &app_id)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        open_with_windows::launch(&file_path, &app_id)
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod open_with_macos {
+    use super::AppInfo;
+    use core_foundation::array::CFArray;
+    use core_foundation::base::{CFRelease, TCFType};
+    use core_foundation::string::CFString;
+    use core_foundation::url::CFURL;
+
+    #[allow(non_upper_case_globals)]
+    const kLSRolesAll: u32 = 0xFFFFFFFF;
+
+    #[link(name = "CoreServices", kind = "framework")]
+    extern "C" {
+        fn LSCopyApplicationURLsForURL(
+            url: core_foundation::url::CFURLRef,
+            role_mask: u32,
+        ) -> core_foundation::array::CFArrayRef;
+        fn LSCopyDisplayNameForURL(
+            url: core_foundation::url::CFURLRef,
+            name: *mut core_foundation::string::CFStringRef,
+        ) -> i32;
+    }
+
+    fn bundle_id(url: &CFURL) -> Option<String> {
+        let bundle = unsafe { core_foundation::bundle::CFBundleCreate(std::ptr::null(), url.as_concrete_TypeRef()) };
+        if bundle.is_null() {
+            return None;
+        }
+        let id = unsafe { core_foundation::bundle::CFBundleGetIdentifier(bundle) };
+        let result = if id.is_null() {
+            None
+        } else {
+            Some(unsafe { CFString::wrap_under_get_rule(id) }.to_string())
+        };
+        unsafe { CFRelease(bundle as *const _) };
+        result
+    }
+
+    pub fn list_apps(file_path: &str) -> Result<Vec<AppInfo>, String> {
+        let url = CFURL::from_path(file_path, false).ok_or("Invalid file path")?;
+        let urls_ref = unsafe { LSCopyApplicationURLsForURL(url.as_concrete_TypeRef(), kLSRolesAll) };
+        if urls_ref.is_null() {
+            return Ok(Vec::new());
+        }
+        let urls: CFArray<CFURL> = unsafe { CFArray::wrap_under_create_rule(urls_ref) };
+
+        let mut apps = Vec::new();
+        for app_url in urls.iter() {
+            let Some(id) = bundle_id(&app_url) else { continue };
+            let mut name_ref: core_foundation::string::CFStringRef = std::ptr::null_mut();
+            unsafe { LSCopyDisplayNameForURL(app_url.as_concrete_TypeRef(), &mut name_ref) };
+            let name = if name_ref.is_null() {
+                id.clone()
+            } else {
+                unsafe { CFString::wrap_under_create_rule(name_ref) }.to_string()
+            };
+            apps.push(AppInfo { id, name });
+        }
+        Ok(apps)
+    }
+
+    pub fn launch(file_path: &str, app_id: &str) -> Result<(), String> {
+        std::process::Command::new("open")
+            .arg("-b")
+            .arg(app_id)
+            .arg(file_path)
+            .spawn()
+            .map_err(|e| format!("Error launching application: {}", e))?;
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod open_with_linux {
+    use super::AppInfo;
+    use gio::prelude::*;
+
+    pub fn list_apps(file_path: &str) -> Result<Vec<AppInfo>, String> {
+        let content_type = gio::content_type_guess(Some(file_path), &[]).0.to_string();
+
+        let apps = gio::AppInfo::all()
+            .into_iter()
+            .filter(|app| app.supports_content_type(&content_type))
+            .filter_map(|app| {
+                Some(AppInfo {
+                    id: app.id()?.to_string(),
+                    name: app.name().to_string(),
+                })
+            })
+            .collect();
+
+        Ok(apps)
+    }
+
+    /// Launch `app_id` (a `.desktop` entry id) with `file_path`. The launch
+    /// context carries a normalized `PATH`/`XDG_*` so apps launched from a
+    /// bundled/AppImage context inherit a sane environment instead of the
+    /// bundle's own — the host app's own process environment is never touched.
+    pub fn launch(file_path: &str, app_id: &str) -> Result<(), String> {
+        let app_info = gio::DesktopAppInfo::new(app_id)
+            .ok_or_else(|| format!("Unknown application: {}", app_id))?;
+
+        let context = gio::AppLaunchContext::new();
+        for (key, clean_value) in normalized_env() {
+            context.setenv(key, &clean_value);
+        }
+
+        let file = gio::File::for_path(file_path);
+        app_info
+            .launch(&[file], Some(&context))
+            .map_err(|e| format!("Error launching application: {}", e))?;
+        Ok(())
+    }
+
+    /// Strip AppImage/bundle injected prefixes from `PATH` and the `XDG_*` base
+    /// directory variables so spawned apps see the host system's paths.
+    fn normalized_env() -> Vec<(&'static str, String)> {
+        let mut cleaned = Vec::new();
+        for key in ["PATH", "XDG_DATA_DIRS", "XDG_CONFIG_DIRS"] {
+            if let Ok(value) = std::env::var(key) {
+                let filtered: Vec<&str> = value
+                    .split(':')
+                    .filter(|segment| !segment.contains("AppDir") && !segment.contains(".mount_"))
+                    .collect();
+                cleaned.push((key, filtered.join(":")));
+            }
+        }
+        cleaned
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod open_with_windows {
+    use super::AppInfo;
+
+    /// Windows has no simple enumeration API exposed here; report the shell's
+    /// default file association as the only entry and let `open_with` fall back
+    /// to it.
+    pub fn list_apps(_file_path: &str) -> Result<Vec<AppInfo>, String> {
+        Ok(vec![AppInfo {
+            id: "default".to_string(),
+            name: "Default Application".to_string(),
+        }])
+    }
+
+    pub fn launch(file_path: &str, _app_id: &str) -> Result<(), String> {
+        std::process::Command::new("cmd")
+            .args(["/C", "start", "", file_path])
+            .spawn()
+            .map_err(|e| format!("Error launching application: {}", e))?;
+        Ok(())
+    }
+}
+
 /// Get Documents folder path
 #[tauri::command]
 pub fn get_documents_folder() -> Result<String, String> {
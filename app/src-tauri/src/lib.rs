@@ -1,14 +1,86 @@
-use tauri::{menu::*, Emitter};
-use std::fs;
+mod file_manager;
+
+use tauri::{menu::*, Emitter, Manager, State, Wry};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Application state
+#[derive(Default)]
+pub struct AppState {
+    pub current_file: String,
+}
+
+/// Holds the built `MenuItem` handles keyed by id, so the frontend can flip
+/// enabled-state/text/accelerator at runtime instead of them being fixed at
+/// menu-build time.
+#[derive(Default)]
+pub struct MenuState {
+    items: Mutex<HashMap<String, MenuItem<Wry>>>,
+}
+
+// Command handlers
+
+#[tauri::command]
+fn handle_new_file(_app_state: State<AppState>) -> Result<String, String> {
+    println!("Creating new document");
+    Ok("New file created".to_string())
+}
+
+#[tauri::command]
+fn handle_open_file(_app_state: State<AppState>) -> Result<String, String> {
+    println!("Opening file");
+    Ok("File opened".to_string())
+}
+
+#[tauri::command]
+fn handle_save_file(_app_state: State<AppState>) -> Result<String, String> {
+    println!("Saving file");
+    Ok("File saved".to_string())
+}
 
 #[tauri::command]
-fn read_file(path: String) -> Result<String, String> {
-    fs::read_to_string(&path).map_err(|e| e.to_string())
+fn handle_command_palette() -> Result<String, String> {
+    println!("Opening command palette");
+    Ok("Command palette opened".to_string())
 }
 
 #[tauri::command]
-fn write_file(path: String, content: String) -> Result<(), String> {
-    fs::write(&path, content).map_err(|e| e.to_string())
+fn handle_settings() -> Result<String, String> {
+    println!("Opening settings");
+    Ok("Settings opened".to_string())
+}
+
+#[tauri::command]
+fn handle_hide_window(window: tauri::Window) -> Result<String, String> {
+    window.hide().map_err(|e| e.to_string())?;
+    Ok("Window hidden".to_string())
+}
+
+/// Update an existing menu item's enabled state, text, and/or accelerator.
+#[tauri::command]
+fn set_menu_state(
+    id: String,
+    enabled: Option<bool>,
+    text: Option<String>,
+    accelerator: Option<String>,
+    state: State<MenuState>,
+) -> Result<(), String> {
+    let items = state.items.lock().map_err(|e| e.to_string())?;
+    let item = items
+        .get(&id)
+        .ok_or_else(|| format!("Unknown menu item: {}", id))?;
+
+    if let Some(enabled) = enabled {
+        item.set_enabled(enabled).map_err(|e| e.to_string())?;
+    }
+    if let Some(text) = text {
+        item.set_text(text).map_err(|e| e.to_string())?;
+    }
+    if let Some(accelerator) = accelerator {
+        item.set_accelerator(Some(accelerator)).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -16,7 +88,40 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
-        .invoke_handler(tauri::generate_handler![read_file, write_file])
+        .manage(AppState::default())
+        .manage(MenuState::default())
+        .manage(file_manager::FileManagerState::default())
+        .invoke_handler(tauri::generate_handler![
+            handle_new_file,
+            handle_open_file,
+            handle_save_file,
+            handle_command_palette,
+            handle_settings,
+            handle_hide_window,
+            set_menu_state,
+            file_manager::get_default_md_folder,
+            file_manager::set_default_md_folder,
+            file_manager::open_file_dialog,
+            file_manager::open_folder_dialog,
+            file_manager::read_file,
+            file_manager::read_file_binary,
+            file_manager::write_file,
+            file_manager::create_new_file,
+            file_manager::list_files,
+            file_manager::read_tree,
+            file_manager::start_watching,
+            file_manager::stop_watching,
+            file_manager::rename_path,
+            file_manager::move_path,
+            file_manager::copy_path,
+            file_manager::delete_path,
+            file_manager::create_folder,
+            file_manager::get_file_info,
+            file_manager::open_in_finder,
+            file_manager::get_open_with_apps,
+            file_manager::open_with,
+            file_manager::get_documents_folder,
+        ])
         .setup(|app| {
             // Create macOS menu with proper Tauri 2 syntax
             let preferences_item = MenuItemBuilder::with_id("preferences", "Preferences...")
@@ -82,6 +187,20 @@ pub fn run() {
 
             app.set_menu(menu)?;
 
+            // Keep the built items around (rather than discarding them) so
+            // `set_menu_state` can look them up by id later.
+            let menu_state = app.state::<MenuState>();
+            let mut items = menu_state.items.lock().unwrap();
+            items.insert("preferences".to_string(), preferences_item);
+            items.insert("open".to_string(), open_item);
+            items.insert("save_as".to_string(), save_as_item);
+            items.insert("format".to_string(), format_item);
+            items.insert("toggle_sidebar".to_string(), toggle_sidebar_item);
+            items.insert("toggle_fullscreen".to_string(), toggle_fullscreen_item);
+            items.insert("zoom_in".to_string(), zoom_in_item);
+            items.insert("zoom_out".to_string(), zoom_out_item);
+            drop(items);
+
             // Handle menu events
             app.on_menu_event(move |app, event| {
                 match event.id().as_ref() {